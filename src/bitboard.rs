@@ -0,0 +1,257 @@
+use std::sync::OnceLock;
+
+use crate::board::{Board, BoardContent, Color, Piece};
+use crate::square::Square;
+
+fn bitboard_index(piece: Piece) -> usize {
+    let (kind, color) = match piece {
+        Piece::Pawn(color) => (0, color),
+        Piece::Knight(color) => (1, color),
+        Piece::Bishop(color) => (2, color),
+        Piece::Rook(color) => (3, color),
+        Piece::Queen(color) => (4, color),
+        Piece::King(color) => (5, color),
+    };
+
+    kind + color.index() * 6
+}
+
+fn piece_at_index(index: usize) -> Piece {
+    let color = if index < 6 { Color::White } else { Color::Black };
+
+    match index % 6 {
+        0 => Piece::Pawn(color),
+        1 => Piece::Knight(color),
+        2 => Piece::Bishop(color),
+        3 => Piece::Rook(color),
+        4 => Piece::Queen(color),
+        _ => Piece::King(color),
+    }
+}
+
+/// The board's pieces as twelve per piece-color bitboards, plus the
+/// combined occupancy of each side. Bit `n` of every bitboard corresponds
+/// to `Square::from_index(n)` (A1 = 0, H8 = 63).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bitboards {
+    pieces: [u64; 12],
+    occupancy: [u64; 2],
+}
+
+impl Bitboards {
+    pub fn piece_at(&self, square: Square) -> Option<Piece> {
+        let bit = 1u64 << usize::from(square);
+        (0..12).find(|&index| self.pieces[index] & bit != 0).map(piece_at_index)
+    }
+
+    pub fn occupancy(&self, color: Color) -> u64 {
+        self.occupancy[color.index()]
+    }
+
+    pub fn all_occupancy(&self) -> u64 {
+        self.occupancy[0] | self.occupancy[1]
+    }
+
+    /// The combined bitboard of `color`'s rooks and queens, the pieces a
+    /// rook-direction ray can be attacked by.
+    pub fn rook_like(&self, color: Color) -> u64 {
+        let base = color.index() * 6;
+        self.pieces[base + 3] | self.pieces[base + 4]
+    }
+
+    /// The combined bitboard of `color`'s bishops and queens, the pieces a
+    /// bishop-direction ray can be attacked by.
+    pub fn bishop_like(&self, color: Color) -> u64 {
+        let base = color.index() * 6;
+        self.pieces[base + 2] | self.pieces[base + 4]
+    }
+
+    /// Converts back to the 8x8 array representation `Board` stores.
+    pub fn to_content(self) -> BoardContent {
+        let mut content: BoardContent = [[None; 8]; 8];
+
+        for index in 0..64 {
+            let square = Square::from_index(index).expect("index is in 0..64");
+            content[7 - square.rank() as usize][square.file() as usize] = self.piece_at(square);
+        }
+
+        content
+    }
+}
+
+impl From<&Board> for Bitboards {
+    fn from(board: &Board) -> Bitboards {
+        let mut pieces = [0u64; 12];
+        let mut occupancy = [0u64; 2];
+
+        for index in 0..64 {
+            let square = Square::from_index(index).expect("index is in 0..64");
+            if let Some(piece) = board.piece_at(square) {
+                let bit = 1u64 << index;
+                pieces[bitboard_index(piece)] |= bit;
+                occupancy[piece.color().index()] |= bit;
+            }
+        }
+
+        Bitboards { pieces, occupancy }
+    }
+}
+
+// Ray directions, ordered so the first four are orthogonal (rook) and the
+// last four are diagonal (bishop). N/E/NE/NW move towards higher square
+// indices; S/W/SE/SW move towards lower ones.
+const DIRECTIONS: [(i8, i8); 8] = [
+    (0, 1),
+    (0, -1),
+    (1, 0),
+    (-1, 0),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+const ROOK_DIRECTIONS: [usize; 4] = [0, 1, 2, 3];
+const BISHOP_DIRECTIONS: [usize; 4] = [4, 5, 6, 7];
+const INCREASING_DIRECTIONS: [usize; 4] = [0, 2, 4, 5];
+
+fn compute_ray(square: Square, direction: (i8, i8)) -> u64 {
+    let mut ray = 0u64;
+    let mut current = square;
+
+    while let Some(next) = current.offset(direction.0, direction.1) {
+        ray |= 1u64 << usize::from(next);
+        current = next;
+    }
+
+    ray
+}
+
+fn rays() -> &'static [[u64; 8]; 64] {
+    static RAYS: OnceLock<[[u64; 8]; 64]> = OnceLock::new();
+    RAYS.get_or_init(|| {
+        let mut table = [[0u64; 8]; 64];
+        for (index, square_rays) in table.iter_mut().enumerate() {
+            let square = Square::from_index(index).expect("index is in 0..64");
+            for (direction_index, direction) in DIRECTIONS.iter().enumerate() {
+                square_rays[direction_index] = compute_ray(square, *direction);
+            }
+        }
+        table
+    })
+}
+
+fn ray_attacks(square: Square, direction_index: usize, occupancy: u64) -> u64 {
+    let ray = rays()[usize::from(square)][direction_index];
+    let blockers = ray & occupancy;
+
+    if blockers == 0 {
+        return ray;
+    }
+
+    let nearest_blocker = if INCREASING_DIRECTIONS.contains(&direction_index) {
+        blockers.trailing_zeros()
+    } else {
+        63 - blockers.leading_zeros()
+    };
+
+    let beyond_blocker = rays()[nearest_blocker as usize][direction_index];
+    ray & !beyond_blocker
+}
+
+/// Rook attacks from `square` given the full-board `occupancy`, masking
+/// each ray at its first blocker in that direction.
+pub fn rook_attacks(square: Square, occupancy: u64) -> u64 {
+    ROOK_DIRECTIONS
+        .iter()
+        .fold(0, |attacks, &direction| attacks | ray_attacks(square, direction, occupancy))
+}
+
+/// Bishop attacks from `square` given the full-board `occupancy`, masking
+/// each ray at its first blocker in that direction.
+pub fn bishop_attacks(square: Square, occupancy: u64) -> u64 {
+    BISHOP_DIRECTIONS
+        .iter()
+        .fold(0, |attacks, &direction| attacks | ray_attacks(square, direction, occupancy))
+}
+
+/// Queen attacks: the union of rook and bishop attacks from `square`.
+pub fn queen_attacks(square: Square, occupancy: u64) -> u64 {
+    rook_attacks(square, occupancy) | bishop_attacks(square, occupancy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bit(square: Square) -> u64 {
+        1u64 << usize::from(square)
+    }
+
+    #[test]
+    fn test_from_board_counts_pieces() {
+        let bitboards = Bitboards::from(&Board::new());
+        assert_eq!(bitboards.all_occupancy().count_ones(), 32);
+        assert_eq!(bitboards.occupancy(Color::White).count_ones(), 16);
+        assert_eq!(bitboards.occupancy(Color::Black).count_ones(), 16);
+    }
+
+    #[test]
+    fn test_rook_like_and_bishop_like_combine_queen() {
+        let bitboards = Bitboards::from(&Board::new());
+
+        assert_eq!(bitboards.rook_like(Color::White), bit(Square::A1) | bit(Square::H1) | bit(Square::D1));
+        assert_eq!(bitboards.bishop_like(Color::White), bit(Square::C1) | bit(Square::F1) | bit(Square::D1));
+    }
+
+    #[test]
+    fn test_from_board_places_pieces_correctly() {
+        let bitboards = Bitboards::from(&Board::new());
+        assert_eq!(bitboards.piece_at(Square::E1), Some(Piece::King(Color::White)));
+        assert_eq!(bitboards.piece_at(Square::E8), Some(Piece::King(Color::Black)));
+        assert_eq!(bitboards.piece_at(Square::E4), None);
+    }
+
+    #[test]
+    fn test_to_content_round_trips_starting_position() {
+        let board = Board::new();
+        let bitboards = Bitboards::from(&board);
+        assert_eq!(bitboards.to_content(), board.content);
+    }
+
+    #[test]
+    fn test_rook_attacks_stop_at_first_blocker() {
+        let occupancy = bit(Square::D6) | bit(Square::F4);
+        let attacks = rook_attacks(Square::D4, occupancy);
+
+        assert!(attacks & bit(Square::D5) != 0);
+        assert!(attacks & bit(Square::D6) != 0);
+        assert!(attacks & bit(Square::D7) == 0);
+        assert!(attacks & bit(Square::E4) != 0);
+        assert!(attacks & bit(Square::F4) != 0);
+        assert!(attacks & bit(Square::G4) == 0);
+        assert!(attacks & bit(Square::D3) != 0);
+        assert!(attacks & bit(Square::D1) != 0);
+    }
+
+    #[test]
+    fn test_bishop_attacks_stop_at_first_blocker() {
+        let occupancy = bit(Square::F6) | bit(Square::B2);
+        let attacks = bishop_attacks(Square::D4, occupancy);
+
+        assert!(attacks & bit(Square::E5) != 0);
+        assert!(attacks & bit(Square::F6) != 0);
+        assert!(attacks & bit(Square::G7) == 0);
+        assert!(attacks & bit(Square::C3) != 0);
+        assert!(attacks & bit(Square::B2) != 0);
+        assert!(attacks & bit(Square::A1) == 0);
+    }
+
+    #[test]
+    fn test_queen_attacks_combine_rook_and_bishop() {
+        let attacks = queen_attacks(Square::D4, 0);
+        assert_eq!(attacks, rook_attacks(Square::D4, 0) | bishop_attacks(Square::D4, 0));
+        assert!(attacks & bit(Square::D8) != 0);
+        assert!(attacks & bit(Square::A1) != 0);
+        assert!(attacks & bit(Square::A4) != 0);
+    }
+}
@@ -0,0 +1,563 @@
+use std::convert::TryInto;
+use std::fmt;
+
+use crate::fen::{FenError, FromFen};
+use crate::square::Square;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Color {
+    White,
+    Black,
+}
+
+impl Color {
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Color::White => 0,
+            Color::Black => 1,
+        }
+    }
+}
+
+/// Castling rights still available to one side.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum CastleRights {
+    #[default]
+    NoSide,
+    KingSide,
+    QueenSide,
+    BothSides,
+}
+
+impl CastleRights {
+    fn with_king_side(self) -> Self {
+        match self {
+            CastleRights::NoSide | CastleRights::KingSide => CastleRights::KingSide,
+            CastleRights::QueenSide | CastleRights::BothSides => CastleRights::BothSides,
+        }
+    }
+
+    fn with_queen_side(self) -> Self {
+        match self {
+            CastleRights::NoSide | CastleRights::QueenSide => CastleRights::QueenSide,
+            CastleRights::KingSide | CastleRights::BothSides => CastleRights::BothSides,
+        }
+    }
+
+    pub(crate) fn without_king_side(self) -> Self {
+        match self {
+            CastleRights::NoSide | CastleRights::KingSide => CastleRights::NoSide,
+            CastleRights::QueenSide | CastleRights::BothSides => CastleRights::QueenSide,
+        }
+    }
+
+    pub(crate) fn without_queen_side(self) -> Self {
+        match self {
+            CastleRights::NoSide | CastleRights::QueenSide => CastleRights::NoSide,
+            CastleRights::KingSide | CastleRights::BothSides => CastleRights::KingSide,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Piece {
+    Rook(Color),
+    Queen(Color),
+    King(Color),
+    Pawn(Color),
+    Bishop(Color),
+    Knight(Color),
+}
+
+impl Piece {
+    /// The color of the side this piece belongs to.
+    pub fn color(self) -> Color {
+        match self {
+            Piece::Rook(color)
+            | Piece::Queen(color)
+            | Piece::King(color)
+            | Piece::Pawn(color)
+            | Piece::Bishop(color)
+            | Piece::Knight(color) => color,
+        }
+    }
+
+    fn to_fen_char(self) -> char {
+        let c = match self {
+            Piece::Pawn(_) => 'p',
+            Piece::Rook(_) => 'r',
+            Piece::Knight(_) => 'n',
+            Piece::Bishop(_) => 'b',
+            Piece::Queen(_) => 'q',
+            Piece::King(_) => 'k',
+        };
+
+        if self.color() == Color::White {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }
+    }
+
+    fn to_unicode(self) -> char {
+        match self {
+            Piece::Pawn(Color::White) => '♙',
+            Piece::Knight(Color::White) => '♘',
+            Piece::Bishop(Color::White) => '♗',
+            Piece::Rook(Color::White) => '♖',
+            Piece::Queen(Color::White) => '♕',
+            Piece::King(Color::White) => '♔',
+            Piece::Pawn(Color::Black) => '♟',
+            Piece::Knight(Color::Black) => '♞',
+            Piece::Bishop(Color::Black) => '♝',
+            Piece::Rook(Color::Black) => '♜',
+            Piece::Queen(Color::Black) => '♛',
+            Piece::King(Color::Black) => '♚',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Piece> {
+        let color = if c.is_ascii_uppercase() {
+            Color::White
+        } else {
+            Color::Black
+        };
+
+        match c.to_ascii_lowercase() {
+            'p' => Some(Piece::Pawn(color)),
+            'r' => Some(Piece::Rook(color)),
+            'n' => Some(Piece::Knight(color)),
+            'b' => Some(Piece::Bishop(color)),
+            'q' => Some(Piece::Queen(color)),
+            'k' => Some(Piece::King(color)),
+            _ => None,
+        }
+    }
+}
+
+pub type Row = [Option<Piece>; 8];
+pub type BoardContent = [Row; 8];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Board {
+    pub content: BoardContent,
+    pub side_to_move: Color,
+    pub castling: [CastleRights; 2],
+    pub en_passant: Option<Square>,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+}
+
+pub const INITIAL_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+fn parse_row(row: &str) -> Result<Row, FenError> {
+    let mut squares: Vec<Option<Piece>> = Vec::new();
+
+    for c in row.chars() {
+        if c.is_ascii_digit() {
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| FenError::InvalidFen(format!("'{}' is not a digit", c)))?;
+            if digit == 0 || digit > 8 {
+                return Err(FenError::InvalidFen(format!(
+                    "run-length digit '{}' must be between 1 and 8",
+                    digit
+                )));
+            }
+
+            squares.append(&mut vec![None; digit as usize]);
+        } else if c.is_ascii_alphabetic() {
+            let piece = Piece::from_char(c)
+                .ok_or_else(|| FenError::InvalidFen(format!("'{}' is not a valid piece letter", c)))?;
+            squares.push(Some(piece));
+        } else {
+            return Err(FenError::InvalidFen(format!(
+                "unexpected character '{}' in piece placement",
+                c
+            )));
+        }
+    }
+
+    if squares.len() != 8 {
+        return Err(FenError::InvalidPosition(format!(
+            "rank '{}' has {} squares, expected 8",
+            row,
+            squares.len()
+        )));
+    }
+
+    squares
+        .try_into()
+        .map_err(|_| FenError::InvalidPosition(format!("rank '{}' has {} squares, expected 8", row, 8)))
+}
+
+fn parse_color(s: &str) -> Result<Color, FenError> {
+    match s {
+        "w" => Ok(Color::White),
+        "b" => Ok(Color::Black),
+        _ => Err(FenError::InvalidFen(format!(
+            "'{}' is not a valid active color, expected 'w' or 'b'",
+            s
+        ))),
+    }
+}
+
+fn parse_castling(s: &str) -> Result<[CastleRights; 2], FenError> {
+    let mut rights = [CastleRights::NoSide, CastleRights::NoSide];
+
+    if s == "-" {
+        return Ok(rights);
+    }
+
+    if s.is_empty() {
+        return Err(FenError::InvalidFen(
+            "castling availability field must not be empty".to_string(),
+        ));
+    }
+
+    for c in s.chars() {
+        match c {
+            'K' => rights[Color::White.index()] = rights[Color::White.index()].with_king_side(),
+            'Q' => rights[Color::White.index()] = rights[Color::White.index()].with_queen_side(),
+            'k' => rights[Color::Black.index()] = rights[Color::Black.index()].with_king_side(),
+            'q' => rights[Color::Black.index()] = rights[Color::Black.index()].with_queen_side(),
+            _ => {
+                return Err(FenError::InvalidFen(format!(
+                    "'{}' is not a valid castling availability field",
+                    s
+                )))
+            }
+        }
+    }
+
+    Ok(rights)
+}
+
+fn parse_en_passant(s: &str) -> Result<Option<Square>, FenError> {
+    if s == "-" {
+        return Ok(None);
+    }
+
+    s.parse().map(Some).map_err(|_| {
+        FenError::InvalidFen(format!("'{}' is not a valid en-passant target square", s))
+    })
+}
+
+impl FromFen for Board {
+    fn from_fen(fen: &str) -> Result<Self, FenError> {
+        let mut fields = fen.split(' ');
+
+        let rows_string = fields
+            .next()
+            .ok_or_else(|| FenError::InvalidFen("missing piece placement field".to_string()))?;
+        let active_color = fields
+            .next()
+            .ok_or_else(|| FenError::InvalidFen("missing active color field".to_string()))?;
+        let castling = fields
+            .next()
+            .ok_or_else(|| FenError::InvalidFen("missing castling availability field".to_string()))?;
+        let en_passant = fields
+            .next()
+            .ok_or_else(|| FenError::InvalidFen("missing en-passant target field".to_string()))?;
+        let halfmove_clock = fields
+            .next()
+            .ok_or_else(|| FenError::InvalidFen("missing halfmove clock field".to_string()))?;
+        let fullmove_number = fields
+            .next()
+            .ok_or_else(|| FenError::InvalidFen("missing fullmove number field".to_string()))?;
+
+        let rows: Vec<&str> = rows_string.split('/').collect();
+        if rows.len() != 8 {
+            return Err(FenError::InvalidPosition(format!(
+                "expected 8 ranks, found {}",
+                rows.len()
+            )));
+        }
+
+        let row_array: Vec<Row> = rows
+            .into_iter()
+            .map(parse_row)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Board {
+            content: row_array
+                .try_into()
+                .map_err(|_| FenError::InvalidPosition("expected 8 ranks, found a different count".to_string()))?,
+            side_to_move: parse_color(active_color)?,
+            castling: parse_castling(castling)?,
+            en_passant: parse_en_passant(en_passant)?,
+            halfmove_clock: halfmove_clock
+                .parse()
+                .map_err(|_| FenError::InvalidFen(format!("'{}' is not a valid halfmove clock", halfmove_clock)))?,
+            fullmove_number: fullmove_number.parse().map_err(|_| {
+                FenError::InvalidFen(format!("'{}' is not a valid fullmove number", fullmove_number))
+            })?,
+        })
+    }
+}
+
+impl Board {
+    pub fn new() -> Self {
+        Board::from_fen(INITIAL_FEN).unwrap()
+    }
+
+    /// The piece occupying `square`, if any.
+    pub fn piece_at(&self, square: Square) -> Option<Piece> {
+        self.content[7 - square.rank() as usize][square.file() as usize]
+    }
+
+    /// Places `piece` on `square`, overwriting whatever was there.
+    pub fn set_piece_at(&mut self, square: Square, piece: Option<Piece>) {
+        self.content[7 - square.rank() as usize][square.file() as usize] = piece;
+    }
+
+    /// Serializes this position back to a FEN string.
+    pub fn to_fen(self) -> String {
+        let placement = self
+            .content
+            .iter()
+            .map(|row| {
+                let mut rank = String::new();
+                let mut empty_run = 0;
+
+                for square in row {
+                    match square {
+                        Some(piece) => {
+                            if empty_run > 0 {
+                                rank.push_str(&empty_run.to_string());
+                                empty_run = 0;
+                            }
+                            rank.push(piece.to_fen_char());
+                        }
+                        None => empty_run += 1,
+                    }
+                }
+
+                if empty_run > 0 {
+                    rank.push_str(&empty_run.to_string());
+                }
+
+                rank
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let active_color = match self.side_to_move {
+            Color::White => 'w',
+            Color::Black => 'b',
+        };
+
+        let mut castling = String::new();
+        if matches!(
+            self.castling[Color::White.index()],
+            CastleRights::KingSide | CastleRights::BothSides
+        ) {
+            castling.push('K');
+        }
+        if matches!(
+            self.castling[Color::White.index()],
+            CastleRights::QueenSide | CastleRights::BothSides
+        ) {
+            castling.push('Q');
+        }
+        if matches!(
+            self.castling[Color::Black.index()],
+            CastleRights::KingSide | CastleRights::BothSides
+        ) {
+            castling.push('k');
+        }
+        if matches!(
+            self.castling[Color::Black.index()],
+            CastleRights::QueenSide | CastleRights::BothSides
+        ) {
+            castling.push('q');
+        }
+        if castling.is_empty() {
+            castling.push('-');
+        }
+
+        let en_passant = self
+            .en_passant
+            .map_or("-".to_string(), |square| square.to_string());
+
+        format!(
+            "{} {} {} {} {} {}",
+            placement, active_color, castling, en_passant, self.halfmove_clock, self.fullmove_number
+        )
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_index, row) in self.content.iter().enumerate() {
+            write!(f, "{} ", 8 - row_index)?;
+            for square in row {
+                let glyph = square.map_or('·', |piece| piece.to_unicode());
+                write!(f, "{} ", glyph)?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "  ")?;
+        for file in 'a'..='h' {
+            write!(f, "{} ", file)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piece_from_char() {
+        assert_eq!(Piece::from_char('P'), Some(Piece::Pawn(Color::White)));
+        assert_eq!(Piece::from_char('p'), Some(Piece::Pawn(Color::Black)));
+        assert_eq!(Piece::from_char('M'), None);
+    }
+
+    #[test]
+    fn test_board_new() {
+        let actual = Board::new().content;
+        let expected: BoardContent = vec![
+            vec![
+                Some(Piece::Rook(Color::Black)),
+                Some(Piece::Knight(Color::Black)),
+                Some(Piece::Bishop(Color::Black)),
+                Some(Piece::Queen(Color::Black)),
+                Some(Piece::King(Color::Black)),
+                Some(Piece::Bishop(Color::Black)),
+                Some(Piece::Knight(Color::Black)),
+                Some(Piece::Rook(Color::Black)),
+            ]
+            .try_into()
+            .unwrap(),
+            vec![
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+                Some(Piece::Pawn(Color::Black)),
+            ]
+            .try_into()
+            .unwrap(),
+            vec![None; 8].try_into().unwrap(),
+            vec![None; 8].try_into().unwrap(),
+            vec![None; 8].try_into().unwrap(),
+            vec![None; 8].try_into().unwrap(),
+            vec![
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+                Some(Piece::Pawn(Color::White)),
+            ]
+            .try_into()
+            .unwrap(),
+            vec![
+                Some(Piece::Rook(Color::White)),
+                Some(Piece::Knight(Color::White)),
+                Some(Piece::Bishop(Color::White)),
+                Some(Piece::Queen(Color::White)),
+                Some(Piece::King(Color::White)),
+                Some(Piece::Bishop(Color::White)),
+                Some(Piece::Knight(Color::White)),
+                Some(Piece::Rook(Color::White)),
+            ]
+            .try_into()
+            .unwrap(),
+        ]
+        .try_into()
+        .unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_fen_rejects_bad_rank_count() {
+        let result = Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1");
+        assert_eq!(
+            result.unwrap_err(),
+            FenError::InvalidPosition("expected 8 ranks, found 7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_fen_rejects_bad_digit() {
+        let result = Board::from_fen("rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        assert!(matches!(result, Err(FenError::InvalidFen(_))));
+    }
+
+    #[test]
+    fn test_from_fen_rejects_invalid_piece_letter() {
+        let result = Board::from_fen("rnbqkbxr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1");
+        assert!(matches!(result, Err(FenError::InvalidFen(_))));
+    }
+
+    #[test]
+    fn test_from_fen_rejects_bad_color() {
+        let result = Board::from_fen(INITIAL_FEN.replace(" w ", " x ").as_str());
+        assert!(matches!(result, Err(FenError::InvalidFen(_))));
+    }
+
+    #[test]
+    fn test_from_fen_parses_castling_rights() {
+        let board = Board::new();
+        assert_eq!(board.castling[Color::White.index()], CastleRights::BothSides);
+        assert_eq!(board.castling[Color::Black.index()], CastleRights::BothSides);
+    }
+
+    #[test]
+    fn test_from_fen_parses_partial_castling_rights() {
+        let board =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Kq - 0 1").unwrap();
+        assert_eq!(board.castling[Color::White.index()], CastleRights::KingSide);
+        assert_eq!(board.castling[Color::Black.index()], CastleRights::QueenSide);
+    }
+
+    #[test]
+    fn test_from_fen_parses_en_passant_target() {
+        let board =
+            Board::from_fen("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2")
+                .unwrap();
+        assert_eq!(board.en_passant, Some(Square::E6));
+    }
+
+    #[test]
+    fn test_piece_at() {
+        let board = Board::new();
+        assert_eq!(board.piece_at(Square::E1), Some(Piece::King(Color::White)));
+        assert_eq!(board.piece_at(Square::E8), Some(Piece::King(Color::Black)));
+        assert_eq!(board.piece_at(Square::E4), None);
+    }
+
+    #[test]
+    fn test_from_fen_rejects_bad_en_passant_target() {
+        let result = Board::from_fen(INITIAL_FEN.replace(" - 0 1", " z9 0 1").as_str());
+        assert!(matches!(result, Err(FenError::InvalidFen(_))));
+    }
+
+    #[test]
+    fn test_to_fen_round_trips() {
+        let fens = [
+            INITIAL_FEN,
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Kq - 0 1",
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2",
+            "4k3/8/8/8/8/8/8/4K2R w K - 3 10",
+        ];
+
+        for fen in fens {
+            let board = Board::from_fen(fen).unwrap();
+            let round_tripped = Board::from_fen(&board.to_fen()).unwrap();
+            assert_eq!(round_tripped, board, "round-trip mismatch for {}", fen);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// Errors produced while parsing a FEN string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FenError {
+    /// The FEN string itself is malformed: missing fields, a bad color
+    /// character, an out-of-range run-length digit, and so on.
+    InvalidFen(String),
+    /// The piece placement field doesn't describe a valid 8x8 position
+    /// (wrong number of ranks, or a rank that doesn't sum to 8 squares).
+    InvalidPosition(String),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FenError::InvalidFen(msg) => write!(f, "invalid FEN: {}", msg),
+            FenError::InvalidPosition(msg) => write!(f, "invalid position: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
+/// Types that can be built from a FEN (Forsyth-Edwards Notation) string.
+pub trait FromFen: Sized {
+    fn from_fen(fen: &str) -> Result<Self, FenError>;
+}
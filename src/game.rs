@@ -0,0 +1,269 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::board::{Board, CastleRights, Color, Piece};
+use crate::moves::{apply_move, Move};
+use crate::square::Square;
+
+/// A move that isn't currently legal in the game's position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IllegalMove;
+
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "illegal move")
+    }
+}
+
+impl std::error::Error for IllegalMove {}
+
+/// The outcome of a game at its current position.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Status {
+    Ongoing,
+    Checkmate,
+    Stalemate,
+    Draw,
+}
+
+/// A chess game: a `Board` plus the position history needed to rule on
+/// the fifty-move rule and threefold repetition.
+pub struct Game {
+    board: Board,
+    position_hashes: Vec<u64>,
+}
+
+/// Hashes the parts of a position that repetition rules care about:
+/// piece placement, side to move, castling rights, and the en-passant
+/// target. The halfmove clock and fullmove number are deliberately
+/// excluded, since they differ between otherwise-identical positions.
+fn hash_position(board: &Board) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    board.content.hash(&mut hasher);
+    board.side_to_move.hash(&mut hasher);
+    board.castling.hash(&mut hasher);
+    board.en_passant.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn castling_rook_move(mv: &Move) -> Option<(Square, Square)> {
+    match (mv.from, mv.to) {
+        (Square::E1, Square::G1) => Some((Square::H1, Square::F1)),
+        (Square::E1, Square::C1) => Some((Square::A1, Square::D1)),
+        (Square::E8, Square::G8) => Some((Square::H8, Square::F8)),
+        (Square::E8, Square::C8) => Some((Square::A8, Square::D8)),
+        _ => None,
+    }
+}
+
+fn updated_castling_rights(board: &Board, mv: &Move, moving_piece: Piece) -> [CastleRights; 2] {
+    let mut rights = board.castling;
+
+    if let Piece::King(color) = moving_piece {
+        rights[color.index()] = CastleRights::NoSide;
+    }
+
+    for square in [mv.from, mv.to] {
+        match square {
+            Square::A1 => rights[Color::White.index()] = rights[Color::White.index()].without_queen_side(),
+            Square::H1 => rights[Color::White.index()] = rights[Color::White.index()].without_king_side(),
+            Square::A8 => rights[Color::Black.index()] = rights[Color::Black.index()].without_queen_side(),
+            Square::H8 => rights[Color::Black.index()] = rights[Color::Black.index()].without_king_side(),
+            _ => {}
+        }
+    }
+
+    rights
+}
+
+fn updated_en_passant(mv: &Move, moving_piece: Piece) -> Option<Square> {
+    let color = match moving_piece {
+        Piece::Pawn(color) => color,
+        _ => return None,
+    };
+
+    if (mv.to.rank() as i8 - mv.from.rank() as i8).abs() != 2 {
+        return None;
+    }
+
+    let forward = if color == Color::White { 1 } else { -1 };
+    mv.from.offset(0, forward)
+}
+
+impl Game {
+    pub fn new() -> Self {
+        let board = Board::new();
+        Game {
+            position_hashes: vec![hash_position(&board)],
+            board,
+        }
+    }
+
+    /// The current position.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Applies `mv` if it's legal in the current position, updating side
+    /// to move, castling rights, the en-passant target, move counters,
+    /// and the position history used for draw detection.
+    pub fn make_move(&mut self, mv: Move) -> Result<(), IllegalMove> {
+        if !self.board.legal_moves().contains(&mv) {
+            return Err(IllegalMove);
+        }
+
+        let moving_piece = self
+            .board
+            .piece_at(mv.from)
+            .expect("a legal move always starts from an occupied square");
+        let is_pawn_move = matches!(moving_piece, Piece::Pawn(_));
+        let is_capture =
+            self.board.piece_at(mv.to).is_some() || (is_pawn_move && Some(mv.to) == self.board.en_passant);
+        let mover = self.board.side_to_move;
+
+        let mut next_board = apply_move(&self.board, &mv);
+
+        if let (Piece::King(color), Some((rook_from, rook_to))) = (moving_piece, castling_rook_move(&mv)) {
+            next_board.set_piece_at(rook_from, None);
+            next_board.set_piece_at(rook_to, Some(Piece::Rook(color)));
+        }
+
+        next_board.castling = updated_castling_rights(&self.board, &mv, moving_piece);
+        next_board.en_passant = updated_en_passant(&mv, moving_piece);
+        next_board.halfmove_clock = if is_pawn_move || is_capture {
+            0
+        } else {
+            self.board.halfmove_clock + 1
+        };
+        next_board.fullmove_number = if mover == Color::Black {
+            self.board.fullmove_number + 1
+        } else {
+            self.board.fullmove_number
+        };
+
+        self.board = next_board;
+        self.position_hashes.push(hash_position(&self.board));
+        Ok(())
+    }
+
+    /// The game's status at the current position.
+    pub fn status(&self) -> Status {
+        if self.board.is_checkmate() {
+            return Status::Checkmate;
+        }
+
+        if self.board.is_stalemate() {
+            return Status::Stalemate;
+        }
+
+        if self.board.halfmove_clock >= 100 || self.is_threefold_repetition() {
+            return Status::Draw;
+        }
+
+        Status::Ongoing
+    }
+
+    fn is_threefold_repetition(&self) -> bool {
+        let current = *self
+            .position_hashes
+            .last()
+            .expect("the starting position is always recorded");
+
+        self.position_hashes.iter().filter(|&&hash| hash == current).count() >= 3
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fen::FromFen;
+
+    fn mv(from: Square, to: Square) -> Move {
+        Move {
+            from,
+            to,
+            promotion: None,
+        }
+    }
+
+    #[test]
+    fn test_make_move_rejects_illegal_move() {
+        let mut game = Game::new();
+        assert_eq!(game.make_move(mv(Square::E2, Square::E5)), Err(IllegalMove));
+    }
+
+    #[test]
+    fn test_make_move_updates_side_to_move_and_counters() {
+        let mut game = Game::new();
+        game.make_move(mv(Square::E2, Square::E4)).unwrap();
+
+        assert_eq!(game.board().side_to_move, Color::Black);
+        assert_eq!(game.board().en_passant, Some(Square::E3));
+        assert_eq!(game.board().halfmove_clock, 0);
+        assert_eq!(game.board().fullmove_number, 1);
+
+        game.make_move(mv(Square::G8, Square::F6)).unwrap();
+        assert_eq!(game.board().fullmove_number, 2);
+        assert_eq!(game.board().en_passant, None);
+        assert_eq!(game.board().halfmove_clock, 1);
+    }
+
+    #[test]
+    fn test_make_move_relocates_rook_on_castling() {
+        let board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let mut game = Game {
+            position_hashes: vec![hash_position(&board)],
+            board,
+        };
+
+        game.make_move(mv(Square::E1, Square::G1)).unwrap();
+
+        assert_eq!(game.board().piece_at(Square::F1), Some(Piece::Rook(Color::White)));
+        assert_eq!(game.board().piece_at(Square::H1), None);
+        assert_eq!(game.board().piece_at(Square::G1), Some(Piece::King(Color::White)));
+        assert_eq!(game.board().castling[Color::White.index()], CastleRights::NoSide);
+    }
+
+    #[test]
+    fn test_status_detects_checkmate() {
+        let board = Board::from_fen("3R2k1/5ppp/8/8/8/8/8/7K b - - 0 1").unwrap();
+        let game = Game {
+            position_hashes: vec![hash_position(&board)],
+            board,
+        };
+
+        assert_eq!(game.status(), Status::Checkmate);
+    }
+
+    #[test]
+    fn test_status_detects_fifty_move_draw() {
+        let board = Board::from_fen("7k/5Q2/8/8/8/8/8/7K w - - 100 60").unwrap();
+        let game = Game {
+            position_hashes: vec![hash_position(&board)],
+            board,
+        };
+
+        assert_eq!(game.status(), Status::Draw);
+    }
+
+    #[test]
+    fn test_status_detects_threefold_repetition() {
+        let mut game = Game::new();
+
+        for _ in 0..2 {
+            game.make_move(mv(Square::G1, Square::F3)).unwrap();
+            game.make_move(mv(Square::G8, Square::F6)).unwrap();
+            game.make_move(mv(Square::F3, Square::G1)).unwrap();
+            game.make_move(mv(Square::F6, Square::G8)).unwrap();
+        }
+
+        assert_eq!(game.status(), Status::Draw);
+    }
+}
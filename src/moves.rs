@@ -0,0 +1,456 @@
+use crate::bitboard::{bishop_attacks, queen_attacks, rook_attacks, Bitboards};
+use crate::board::{Board, CastleRights, Color, Piece};
+use crate::square::Square;
+
+/// A move from one square to another, with an optional promotion piece.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Move {
+    pub from: Square,
+    pub to: Square,
+    pub promotion: Option<Piece>,
+}
+
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+const KING_OFFSETS: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+const PROMOTION_PIECES: [fn(Color) -> Piece; 4] =
+    [Piece::Queen, Piece::Rook, Piece::Bishop, Piece::Knight];
+
+fn opponent(color: Color) -> Color {
+    match color {
+        Color::White => Color::Black,
+        Color::Black => Color::White,
+    }
+}
+
+/// Pushes a move from `from` to every square set in `targets`, a bitboard
+/// of attacked squares with the mover's own pieces already masked out.
+fn add_bitboard_moves(from: Square, targets: u64, moves: &mut Vec<Move>) {
+    let mut remaining = targets;
+    while remaining != 0 {
+        let to_index = remaining.trailing_zeros() as usize;
+        let to = Square::from_index(to_index).expect("bit index is in 0..64");
+        moves.push(Move {
+            from,
+            to,
+            promotion: None,
+        });
+        remaining &= remaining - 1;
+    }
+}
+
+fn add_step_moves(board: &Board, from: Square, color: Color, offsets: &[(i8, i8)], moves: &mut Vec<Move>) {
+    for &(df, dr) in offsets {
+        if let Some(to) = from.offset(df, dr) {
+            let blocked_by_own_piece = board.piece_at(to).is_some_and(|piece| piece.color() == color);
+            if !blocked_by_own_piece {
+                moves.push(Move {
+                    from,
+                    to,
+                    promotion: None,
+                });
+            }
+        }
+    }
+}
+
+fn add_pawn_moves(board: &Board, from: Square, color: Color, moves: &mut Vec<Move>) {
+    let (forward, start_rank, promotion_rank) = match color {
+        Color::White => (1, 1, 7),
+        Color::Black => (-1, 6, 0),
+    };
+
+    let push_with_promotion = |to: Square, moves: &mut Vec<Move>| {
+        if to.rank() == promotion_rank {
+            for make_piece in PROMOTION_PIECES {
+                moves.push(Move {
+                    from,
+                    to,
+                    promotion: Some(make_piece(color)),
+                });
+            }
+        } else {
+            moves.push(Move {
+                from,
+                to,
+                promotion: None,
+            });
+        }
+    };
+
+    if let Some(single) = from.offset(0, forward) {
+        if board.piece_at(single).is_none() {
+            push_with_promotion(single, moves);
+
+            if from.rank() == start_rank {
+                if let Some(double) = from.offset(0, forward * 2) {
+                    if board.piece_at(double).is_none() {
+                        moves.push(Move {
+                            from,
+                            to: double,
+                            promotion: None,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    for &file_offset in &[-1, 1] {
+        let target = match from.offset(file_offset, forward) {
+            Some(target) => target,
+            None => continue,
+        };
+
+        let is_capture = board.piece_at(target).is_some_and(|piece| piece.color() != color);
+        let is_en_passant = board.en_passant == Some(target);
+
+        if is_capture {
+            push_with_promotion(target, moves);
+        } else if is_en_passant {
+            moves.push(Move {
+                from,
+                to: target,
+                promotion: None,
+            });
+        }
+    }
+}
+
+fn add_castling_moves(board: &Board, color: Color, moves: &mut Vec<Move>) {
+    let by_opponent = opponent(color);
+    let rights = board.castling[color.index()];
+
+    let (king_square, king_side_path, king_side_target, queen_side_transit, queen_side_knight_square, queen_side_target) =
+        match color {
+            Color::White => (
+                Square::E1,
+                [Square::F1, Square::G1],
+                Square::G1,
+                [Square::D1, Square::C1],
+                Square::B1,
+                Square::C1,
+            ),
+            Color::Black => (
+                Square::E8,
+                [Square::F8, Square::G8],
+                Square::G8,
+                [Square::D8, Square::C8],
+                Square::B8,
+                Square::C8,
+            ),
+        };
+
+    if is_square_attacked(board, king_square, by_opponent) {
+        return;
+    }
+
+    let can_castle_king_side = matches!(rights, CastleRights::KingSide | CastleRights::BothSides);
+    let can_castle_queen_side = matches!(rights, CastleRights::QueenSide | CastleRights::BothSides);
+
+    if can_castle_king_side
+        && king_side_path.iter().all(|&square| board.piece_at(square).is_none())
+        && king_side_path.iter().all(|&square| !is_square_attacked(board, square, by_opponent))
+    {
+        moves.push(Move {
+            from: king_square,
+            to: king_side_target,
+            promotion: None,
+        });
+    }
+
+    if can_castle_queen_side
+        && board.piece_at(queen_side_knight_square).is_none()
+        && queen_side_transit.iter().all(|&square| board.piece_at(square).is_none())
+        && queen_side_transit.iter().all(|&square| !is_square_attacked(board, square, by_opponent))
+    {
+        moves.push(Move {
+            from: king_square,
+            to: queen_side_target,
+            promotion: None,
+        });
+    }
+}
+
+fn pseudo_legal_moves(board: &Board) -> Vec<Move> {
+    let color = board.side_to_move;
+    let mut moves = Vec::new();
+
+    let bitboards = Bitboards::from(board);
+    let occupancy = bitboards.all_occupancy();
+    let own_occupancy = bitboards.occupancy(color);
+
+    for index in 0..64 {
+        let square = Square::from_index(index).expect("index is in 0..64");
+        let piece = match board.piece_at(square) {
+            Some(piece) if piece.color() == color => piece,
+            _ => continue,
+        };
+
+        match piece {
+            Piece::Pawn(_) => add_pawn_moves(board, square, color, &mut moves),
+            Piece::Knight(_) => add_step_moves(board, square, color, &KNIGHT_OFFSETS, &mut moves),
+            Piece::King(_) => {
+                add_step_moves(board, square, color, &KING_OFFSETS, &mut moves);
+                add_castling_moves(board, color, &mut moves);
+            }
+            Piece::Rook(_) => add_bitboard_moves(square, rook_attacks(square, occupancy) & !own_occupancy, &mut moves),
+            Piece::Bishop(_) => {
+                add_bitboard_moves(square, bishop_attacks(square, occupancy) & !own_occupancy, &mut moves)
+            }
+            Piece::Queen(_) => {
+                add_bitboard_moves(square, queen_attacks(square, occupancy) & !own_occupancy, &mut moves)
+            }
+        }
+    }
+
+    moves
+}
+
+/// Whether any piece of `by_color` attacks `square` on `board`.
+pub(crate) fn is_square_attacked(board: &Board, square: Square, by_color: Color) -> bool {
+    for &(df, dr) in &KNIGHT_OFFSETS {
+        if let Some(from) = square.offset(df, dr) {
+            if matches!(board.piece_at(from), Some(Piece::Knight(c)) if c == by_color) {
+                return true;
+            }
+        }
+    }
+
+    for &(df, dr) in &KING_OFFSETS {
+        if let Some(from) = square.offset(df, dr) {
+            if matches!(board.piece_at(from), Some(Piece::King(c)) if c == by_color) {
+                return true;
+            }
+        }
+    }
+
+    // A pawn of `by_color` attacks diagonally forward, so to find one we
+    // look one rank back from `square` relative to `by_color`'s direction.
+    let pawn_attacker_rank_offset = match by_color {
+        Color::White => -1,
+        Color::Black => 1,
+    };
+    for &file_offset in &[-1, 1] {
+        if let Some(from) = square.offset(file_offset, pawn_attacker_rank_offset) {
+            if matches!(board.piece_at(from), Some(Piece::Pawn(c)) if c == by_color) {
+                return true;
+            }
+        }
+    }
+
+    let bitboards = Bitboards::from(board);
+    let occupancy = bitboards.all_occupancy();
+
+    if rook_attacks(square, occupancy) & bitboards.rook_like(by_color) != 0 {
+        return true;
+    }
+    if bishop_attacks(square, occupancy) & bitboards.bishop_like(by_color) != 0 {
+        return true;
+    }
+
+    false
+}
+
+fn king_square(board: &Board, color: Color) -> Option<Square> {
+    (0..64).find_map(|index| {
+        let square = Square::from_index(index).expect("index is in 0..64");
+        match board.piece_at(square) {
+            Some(Piece::King(c)) if c == color => Some(square),
+            _ => None,
+        }
+    })
+}
+
+/// Applies `mv` to `board`, returning the resulting position. Only updates
+/// piece placement and `side_to_move`; callers that need full game-state
+/// bookkeeping (castling rights, en-passant target, move counters, rook
+/// relocation on castling) must handle that themselves.
+pub(crate) fn apply_move(board: &Board, mv: &Move) -> Board {
+    let mut result = *board;
+    let moving_piece = board.piece_at(mv.from);
+
+    if Some(mv.to) == board.en_passant {
+        if let Some(Piece::Pawn(color)) = moving_piece {
+            let captured_rank_offset = match color {
+                Color::White => -1,
+                Color::Black => 1,
+            };
+            if let Some(captured_square) = mv.to.offset(0, captured_rank_offset) {
+                result.set_piece_at(captured_square, None);
+            }
+        }
+    }
+
+    result.set_piece_at(mv.from, None);
+    result.set_piece_at(mv.to, mv.promotion.or(moving_piece));
+    result.side_to_move = opponent(board.side_to_move);
+    result
+}
+
+impl Board {
+    /// All legal moves for the side to move: pseudo-legal moves with any
+    /// move that would leave the mover's own king in check filtered out.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let color = self.side_to_move;
+
+        pseudo_legal_moves(self)
+            .into_iter()
+            .filter(|mv| {
+                let after = apply_move(self, mv);
+                let king_square = king_square(&after, color).expect("each side has exactly one king");
+                !is_square_attacked(&after, king_square, opponent(color))
+            })
+            .collect()
+    }
+
+    /// Whether `color`'s king is currently attacked.
+    pub fn is_in_check(&self, color: Color) -> bool {
+        let king_square = king_square(self, color).expect("each side has exactly one king");
+        is_square_attacked(self, king_square, opponent(color))
+    }
+
+    /// The side to move is in check and has no legal move to escape it.
+    pub fn is_checkmate(&self) -> bool {
+        self.is_in_check(self.side_to_move) && self.legal_moves().is_empty()
+    }
+
+    /// The side to move is not in check but has no legal move at all.
+    pub fn is_stalemate(&self) -> bool {
+        !self.is_in_check(self.side_to_move) && self.legal_moves().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fen::FromFen;
+
+    #[test]
+    fn test_starting_position_has_twenty_legal_moves() {
+        assert_eq!(Board::new().legal_moves().len(), 20);
+    }
+
+    #[test]
+    fn test_knight_moves_from_starting_square() {
+        let board = Board::new();
+        let knight_moves: Vec<Move> = board
+            .legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == Square::B1)
+            .collect();
+
+        assert_eq!(knight_moves.len(), 2);
+        assert!(knight_moves.iter().any(|mv| mv.to == Square::A3));
+        assert!(knight_moves.iter().any(|mv| mv.to == Square::C3));
+    }
+
+    #[test]
+    fn test_king_cannot_move_into_check() {
+        // A black rook holds the whole e-file: the white king can step
+        // off it but can never land on another e-file square.
+        let board = Board::from_fen("4r3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let king_moves: Vec<Move> = board
+            .legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == Square::E1)
+            .collect();
+
+        assert!(!king_moves.iter().any(|mv| mv.to.file() == Square::E1.file()));
+        assert!(king_moves.iter().any(|mv| mv.to == Square::D1));
+        assert!(king_moves.iter().any(|mv| mv.to == Square::F1));
+    }
+
+    #[test]
+    fn test_en_passant_capture_is_generated() {
+        let board = Board::from_fen("4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1").unwrap();
+        let en_passant_move = board
+            .legal_moves()
+            .into_iter()
+            .find(|mv| mv.from == Square::D4 && mv.to == Square::E3);
+
+        assert!(en_passant_move.is_some());
+    }
+
+    #[test]
+    fn test_pawn_promotes_to_all_four_pieces() {
+        let board = Board::from_fen("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let promotions: Vec<Move> = board
+            .legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == Square::A7 && mv.to == Square::A8)
+            .collect();
+
+        assert_eq!(promotions.len(), 4);
+        assert!(promotions
+            .iter()
+            .all(|mv| matches!(mv.promotion, Some(Piece::Queen(_) | Piece::Rook(_) | Piece::Bishop(_) | Piece::Knight(_)))));
+    }
+
+    #[test]
+    fn test_castling_generated_when_clear_and_safe() {
+        let board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let king_moves: Vec<Move> = board
+            .legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == Square::E1)
+            .collect();
+
+        assert!(king_moves.iter().any(|mv| mv.to == Square::G1));
+        assert!(king_moves.iter().any(|mv| mv.to == Square::C1));
+    }
+
+    #[test]
+    fn test_castling_blocked_through_attacked_square() {
+        // Black rook on f8 covers f1, so white can't castle kingside.
+        let board = Board::from_fen("r4rk1/8/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        let king_moves: Vec<Move> = board
+            .legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == Square::E1)
+            .collect();
+
+        assert!(!king_moves.iter().any(|mv| mv.to == Square::G1));
+        assert!(king_moves.iter().any(|mv| mv.to == Square::C1));
+    }
+
+    #[test]
+    fn test_is_in_check() {
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/4K2r w - - 0 1").unwrap();
+        assert!(board.is_in_check(Color::White));
+        assert!(!board.is_in_check(Color::Black));
+    }
+
+    #[test]
+    fn test_is_checkmate_for_back_rank_mate() {
+        let board = Board::from_fen("3R2k1/5ppp/8/8/8/8/8/7K b - - 0 1").unwrap();
+        assert!(board.is_in_check(Color::Black));
+        assert!(board.is_checkmate());
+    }
+
+    #[test]
+    fn test_is_stalemate() {
+        let board = Board::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert!(!board.is_in_check(Color::Black));
+        assert!(board.is_stalemate());
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// One of the 64 squares of the board, ordered so that `A1 == 0` and
+/// `H8 == 63` with files varying fastest (`A1, B1, ..., H1, A2, ...`).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(u8)]
+pub enum Square {
+    A1, B1, C1, D1, E1, F1, G1, H1,
+    A2, B2, C2, D2, E2, F2, G2, H2,
+    A3, B3, C3, D3, E3, F3, G3, H3,
+    A4, B4, C4, D4, E4, F4, G4, H4,
+    A5, B5, C5, D5, E5, F5, G5, H5,
+    A6, B6, C6, D6, E6, F6, G6, H6,
+    A7, B7, C7, D7, E7, F7, G7, H7,
+    A8, B8, C8, D8, E8, F8, G8, H8,
+}
+
+pub const SQUARES: [Square; 64] = [
+    Square::A1, Square::B1, Square::C1, Square::D1, Square::E1, Square::F1, Square::G1, Square::H1,
+    Square::A2, Square::B2, Square::C2, Square::D2, Square::E2, Square::F2, Square::G2, Square::H2,
+    Square::A3, Square::B3, Square::C3, Square::D3, Square::E3, Square::F3, Square::G3, Square::H3,
+    Square::A4, Square::B4, Square::C4, Square::D4, Square::E4, Square::F4, Square::G4, Square::H4,
+    Square::A5, Square::B5, Square::C5, Square::D5, Square::E5, Square::F5, Square::G5, Square::H5,
+    Square::A6, Square::B6, Square::C6, Square::D6, Square::E6, Square::F6, Square::G6, Square::H6,
+    Square::A7, Square::B7, Square::C7, Square::D7, Square::E7, Square::F7, Square::G7, Square::H7,
+    Square::A8, Square::B8, Square::C8, Square::D8, Square::E8, Square::F8, Square::G8, Square::H8,
+];
+
+impl Square {
+    /// Builds a square from a 0-63 index, or `None` if out of range.
+    pub fn from_index(index: usize) -> Option<Square> {
+        SQUARES.get(index).copied()
+    }
+
+    /// File, 0-indexed from the `a`-file.
+    pub fn file(self) -> u8 {
+        (usize::from(self) % 8) as u8
+    }
+
+    /// Rank, 0-indexed from the first rank.
+    pub fn rank(self) -> u8 {
+        (usize::from(self) / 8) as u8
+    }
+
+    fn from_file_rank(file: i8, rank: i8) -> Option<Square> {
+        if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+            return None;
+        }
+
+        Square::from_index((rank as usize) * 8 + (file as usize))
+    }
+
+    /// The square one rank up (towards the eighth rank), if any.
+    pub fn up(self) -> Option<Square> {
+        Square::from_file_rank(self.file() as i8, self.rank() as i8 + 1)
+    }
+
+    /// The square one rank down (towards the first rank), if any.
+    pub fn down(self) -> Option<Square> {
+        Square::from_file_rank(self.file() as i8, self.rank() as i8 - 1)
+    }
+
+    /// The square one file to the left (towards the a-file), if any.
+    pub fn left(self) -> Option<Square> {
+        Square::from_file_rank(self.file() as i8 - 1, self.rank() as i8)
+    }
+
+    /// The square one file to the right (towards the h-file), if any.
+    pub fn right(self) -> Option<Square> {
+        Square::from_file_rank(self.file() as i8 + 1, self.rank() as i8)
+    }
+
+    /// The square reached by moving `files` files and `ranks` ranks from
+    /// this one, or `None` if that would fall off the board.
+    pub fn offset(self, files: i8, ranks: i8) -> Option<Square> {
+        Square::from_file_rank(self.file() as i8 + files, self.rank() as i8 + ranks)
+    }
+}
+
+impl From<Square> for usize {
+    fn from(square: Square) -> usize {
+        square as usize
+    }
+}
+
+impl FromStr for Square {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Square, String> {
+        let mut chars = s.chars();
+        let file = chars.next();
+        let rank = chars.next();
+
+        match (file, rank, chars.next()) {
+            (Some(file), Some(rank), None) if ('a'..='h').contains(&file) && ('1'..='8').contains(&rank) => {
+                let file = file as i8 - 'a' as i8;
+                let rank = rank as i8 - '1' as i8;
+                Ok(Square::from_file_rank(file, rank).expect("validated file/rank is in range"))
+            }
+            _ => Err(format!("'{}' is not a valid square in algebraic notation", s)),
+        }
+    }
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = (b'a' + self.file()) as char;
+        let rank = (b'1' + self.rank()) as char;
+        write!(f, "{}{}", file, rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_round_trip() {
+        assert_eq!(usize::from(Square::A1), 0);
+        assert_eq!(usize::from(Square::H8), 63);
+        assert_eq!(Square::from_index(0), Some(Square::A1));
+        assert_eq!(Square::from_index(63), Some(Square::H8));
+        assert_eq!(Square::from_index(64), None);
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("e4".parse::<Square>(), Ok(Square::E4));
+        assert_eq!(Square::E4.to_string(), "e4");
+        assert!("i4".parse::<Square>().is_err());
+        assert!("e9".parse::<Square>().is_err());
+        assert!("e".parse::<Square>().is_err());
+    }
+
+    #[test]
+    fn test_neighbors_respect_edges() {
+        assert_eq!(Square::A1.left(), None);
+        assert_eq!(Square::A1.down(), None);
+        assert_eq!(Square::A1.up(), Some(Square::A2));
+        assert_eq!(Square::A1.right(), Some(Square::B1));
+        assert_eq!(Square::H8.right(), None);
+        assert_eq!(Square::H8.up(), None);
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(Square::D4.offset(1, 1), Some(Square::E5));
+        assert_eq!(Square::D4.offset(-4, 0), None);
+        assert_eq!(Square::B1.offset(-1, 2), Some(Square::A3));
+    }
+}